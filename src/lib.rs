@@ -1,8 +1,10 @@
 #![feature(step_trait)]
 #![feature(box_syntax)]
 
-use std::iter::Step;
+use std::cmp;
+use std::iter::{FromIterator, Step};
 use std::mem;
+use std::ops::{BitAnd, BitOr, BitXor, Sub};
 
 type Link<T> = Option<Box<Node<T>>>;
 
@@ -12,11 +14,15 @@ pub struct Diet<T: Ord + Step> {
 }
 
 
-// `Node` in a `Diet`
+// `Node` in a `Diet`, rebalanced with AVL rotations on `height` (see
+// `Node::rebalance`) and augmented with `count`,
+// the number of discrete integers held by its subtree.
 struct Node<T: Ord + Step> {
     segment: Segment<T>,
     left: Link<T>,
     right: Link<T>,
+    height: u8,
+    count: usize,
 }
 
 // The original paper calls it interval,
@@ -29,47 +35,150 @@ pub struct Segment<T> {
 
 impl<T: Ord + Step> Node<T> {
     pub fn new(segment: Segment<T>) -> Self {
+        let count = Node::segment_len(&segment);
         Node {
             segment: segment,
             left: None,
             right: None,
+            height: 1,
+            count: count,
         }
     }
 
-    /// Remove all intervals adjacent to `left` and return the leftmost boundary
-    /// to extend the root.
-    pub fn consume_left_link(link: &mut Link<T>, left: T) -> T {
-        let leftptr;
-        if let Some(ref mut node) = *link {
-            if node.segment.right.add_one() < left {
-                // This one is not adjacent, just descend into the right subtree.
-                return Node::consume_left_link(&mut node.right, left);
-            } else {
-                // Adjacent, consume it along with its right subtree.
+    /// Number of discrete integers covered by `segment`.
+    fn segment_len(segment: &Segment<T>) -> usize {
+        T::steps_between(&segment.left, &segment.right).map_or(0, |steps| steps + 1)
+    }
+
+    fn height(link: &Link<T>) -> u8 {
+        link.as_ref().map_or(0, |node| node.height)
+    }
+
+    /// Split `self` into its segment and its two child links, borrowed
+    /// simultaneously and disjointly, for `DietIterMut` to thread through the
+    /// stack.
+    fn split_mut(&mut self) -> (&mut Segment<T>, &mut Link<T>, &mut Link<T>) {
+        (&mut self.segment, &mut self.left, &mut self.right)
+    }
+
+    fn count(link: &Link<T>) -> usize {
+        link.as_ref().map_or(0, |node| node.count)
+    }
+
+    /// Number of nodes (maximal segments) in the subtree rooted at `link`.
+    fn node_count(link: &Link<T>) -> usize {
+        link.as_ref()
+            .map_or(0, |node| 1 + Node::node_count(&node.left) + Node::node_count(&node.right))
+    }
+
+    /// Recompute `height` and `count` from the current segment and children.
+    fn update(&mut self) {
+        self.height = 1 + cmp::max(Node::height(&self.left), Node::height(&self.right));
+        self.count = Node::segment_len(&self.segment) + Node::count(&self.left) + Node::count(&self.right);
+    }
+
+    fn balance_factor(&self) -> i8 {
+        Node::height(&self.left) as i8 - Node::height(&self.right) as i8
+    }
 
-                // Detach left pointer
-                leftptr = mem::replace(&mut node.left, None);
-                // Fall through to release `node` which borrows from link
+    fn balance_factor_link(link: &Link<T>) -> i8 {
+        link.as_ref().map_or(0, |node| node.balance_factor())
+    }
+
+    /// Single right rotation: promote `link`'s left child.
+    fn rotate_right(link: &mut Link<T>) {
+        let mut node = link.take().unwrap();
+        let mut left = node.left.take().unwrap();
+        node.left = left.right.take();
+        node.update();
+        left.right = Some(node);
+        left.update();
+        *link = Some(left);
+    }
+
+    /// Single left rotation: promote `link`'s right child.
+    fn rotate_left(link: &mut Link<T>) {
+        let mut node = link.take().unwrap();
+        let mut right = node.right.take().unwrap();
+        node.right = right.left.take();
+        node.update();
+        right.left = Some(node);
+        right.update();
+        *link = Some(right);
+    }
+
+    /// Recompute `link`'s height and, if its balance factor has left the
+    /// `[-1, 1]` range, apply one of the standard LL/RR/LR/RL rotations.
+    /// One rotation restores a factor of ±2; a child subtree that shrank by
+    /// more than one level (several nodes dropped at once by a merging insert
+    /// or a range removal) can leave the subtree still out of balance.
+    fn rebalance(link: &mut Link<T>) {
+        let bf = if let Some(ref mut node) = *link {
+            node.update();
+            let bf = node.balance_factor();
+            if bf > 1 && Node::balance_factor_link(&node.left) < 0 {
+                // LR case: left-rotate the left child first.
+                Node::rotate_left(&mut node.left);
+                node.update();
+            } else if bf < -1 && Node::balance_factor_link(&node.right) > 0 {
+                // RL case: right-rotate the right child first.
+                Node::rotate_right(&mut node.right);
+                node.update();
             }
+            node.balance_factor()
         } else {
-            return left;
+            return;
+        };
+        if bf > 1 {
+            Node::rotate_right(link);
+        } else if bf < -1 {
+            Node::rotate_left(link);
         }
-        mem::replace(link, leftptr).unwrap().segment.left
+    }
+
+    /// Remove all intervals adjacent to `left` and return the leftmost boundary
+    /// to extend the root.
+    pub fn consume_left_link(link: &mut Link<T>, left: T) -> T {
+        let adjacent = match *link {
+            Some(ref node) => node.segment.right.add_one() >= left,
+            None => return left,
+        };
+        let result = if adjacent {
+            // Adjacent, consume it along with its right subtree. The merged
+            // boundary is whichever of the two is further left: `left` may
+            // already reach past this node's own stored bound, so keep
+            // consuming from the left subtree promoted into its place.
+            let node = link.as_mut().unwrap();
+            let leftptr = mem::replace(&mut node.left, None);
+            let absorbed = mem::replace(link, leftptr).unwrap().segment.left;
+            Node::consume_left_link(link, cmp::min(absorbed, left))
+        } else {
+            // This one is not adjacent, just descend into the right subtree.
+            Node::consume_left_link(&mut link.as_mut().unwrap().right, left)
+        };
+        Node::rebalance(link);
+        result
     }
 
     /// Similar to consume_right_link
     pub fn consume_right_link(link: &mut Link<T>, right: T) -> T {
-        let rightptr;
-        if let Some(ref mut node) = *link {
-            if node.segment.left.sub_one() > right {
-                return Node::consume_right_link(&mut node.left, right);
-            } else {
-                rightptr = mem::replace(&mut node.right, None);
-            }
+        let adjacent = match *link {
+            Some(ref node) => node.segment.left.sub_one() <= right,
+            None => return right,
+        };
+        let result = if adjacent {
+            // Merged boundary is whichever of the two is further right:
+            // `right` may already reach past this node's own stored bound,
+            // so keep consuming from the right subtree promoted in its place.
+            let node = link.as_mut().unwrap();
+            let rightptr = mem::replace(&mut node.right, None);
+            let absorbed = mem::replace(link, rightptr).unwrap().segment.right;
+            Node::consume_right_link(link, cmp::max(absorbed, right))
         } else {
-            return right;
-        }
-        mem::replace(link, rightptr).unwrap().segment.right
+            Node::consume_right_link(&mut link.as_mut().unwrap().left, right)
+        };
+        Node::rebalance(link);
+        result
     }
 
     pub fn insert_link(link: &mut Link<T>, segment: Segment<T>) {
@@ -77,7 +186,9 @@ impl<T: Ord + Step> Node<T> {
             node.insert(segment);
         } else {
             *link = Some(box Node::new(segment));
+            return;
         }
+        Node::rebalance(link);
     }
 
     pub fn insert(&mut self, segment: Segment<T>) {
@@ -125,6 +236,161 @@ impl<T: Ord + Step> Node<T> {
             true
         }
     }
+
+    /// Returns the `k`-th smallest integer (0-indexed) held by the subtree
+    /// rooted at `link`, descending via per-node `count`s.
+    fn nth(link: &Link<T>, k: usize) -> Option<T> {
+        let node = match *link {
+            Some(ref node) => node,
+            None => return None,
+        };
+        let left_count = Node::count(&node.left);
+        if k < left_count {
+            Node::nth(&node.left, k)
+        } else {
+            let offset = k - left_count;
+            let segment_len = Node::segment_len(&node.segment);
+            if offset < segment_len {
+                Some(node.segment.left.add_usize(offset).unwrap())
+            } else {
+                Node::nth(&node.right, offset - segment_len)
+            }
+        }
+    }
+
+    /// Returns the number of integers held by the subtree rooted at `link`
+    /// that are strictly less than `value`.
+    fn rank(link: &Link<T>, value: &T) -> usize {
+        let node = match *link {
+            Some(ref node) => node,
+            None => return 0,
+        };
+        if *value <= node.segment.left {
+            Node::rank(&node.left, value)
+        } else if *value > node.segment.right {
+            Node::count(&node.left) + Node::segment_len(&node.segment) + Node::rank(&node.right, value)
+        } else {
+            Node::count(&node.left) + T::steps_between(&node.segment.left, value).unwrap_or(0)
+        }
+    }
+
+    /// Build a perfectly balanced subtree from `segments`, a sorted slice of
+    /// pairwise disjoint, non-adjacent segments, by recursively taking the
+    /// middle element as each subtree's root.
+    fn build_balanced(segments: &[Segment<T>]) -> Link<T> {
+        if segments.is_empty() {
+            return None;
+        }
+        let mid = segments.len() / 2;
+        let root = &segments[mid];
+        let mut node = Node::new(Segment::new(root.left.clone(), root.right.clone()));
+        node.left = Node::build_balanced(&segments[..mid]);
+        node.right = Node::build_balanced(&segments[mid + 1..]);
+        node.update();
+        Some(box node)
+    }
+
+    /// Detach and return the node holding the greatest segment in the subtree
+    /// rooted at `*link`, re-linking its left subtree in its place.
+    fn remove_max(link: &mut Link<T>) -> Box<Node<T>> {
+        let mut node = link.take().unwrap();
+        if node.right.is_some() {
+            let max = Node::remove_max(&mut node.right);
+            *link = Some(node);
+            Node::rebalance(link);
+            max
+        } else {
+            *link = node.left.take();
+            node
+        }
+    }
+
+    /// Unlink the node at `*link`, joining its two children into its place.
+    fn unlink(link: &mut Link<T>) {
+        let node = link.take().unwrap();
+        let mut left = node.left;
+        let right = node.right;
+        *link = match (left.is_some(), right.is_some()) {
+            (false, false) => None,
+            (true, false) => left,
+            (false, true) => right,
+            (true, true) => {
+                let mut predecessor = Node::remove_max(&mut left);
+                predecessor.left = left;
+                predecessor.right = right;
+                predecessor.update();
+                Some(predecessor)
+            }
+        };
+    }
+
+    /// Remove `segment` from the subtree rooted at `*link`.
+    pub fn remove_link(link: &mut Link<T>, segment: &Segment<T>) {
+        let (l, r) = (&segment.left, &segment.right);
+        let go_left;
+        let go_right;
+        if let Some(ref node) = *link {
+            // `steps_between` never steps past `T::MIN`/`T::MAX`, unlike
+            // `sub_one`/`add_one`, which would overflow when this node's
+            // segment is flush against a bound with nothing beyond it.
+            go_left = match T::steps_between(r, &node.segment.left) {
+                Some(gap) => gap > 1,
+                None => false,
+            };
+            go_right = !go_left
+                && match T::steps_between(&node.segment.right, l) {
+                    Some(gap) => gap > 1,
+                    None => false,
+                };
+        } else {
+            return;
+        }
+        if go_left {
+            Node::remove_link(&mut link.as_mut().unwrap().left, segment);
+            Node::rebalance(link);
+            return;
+        }
+        if go_right {
+            Node::remove_link(&mut link.as_mut().unwrap().right, segment);
+            Node::rebalance(link);
+            return;
+        }
+        // `segment` overlaps this node's segment; clip, split, or unlink it.
+        let fully_covered;
+        let strictly_inside;
+        let trim_left;
+        {
+            let node = link.as_ref().unwrap();
+            fully_covered = *l <= node.segment.left && node.segment.right <= *r;
+            strictly_inside = !fully_covered && node.segment.left < *l && *r < node.segment.right;
+            trim_left = *l <= node.segment.left;
+        }
+        if fully_covered {
+            // `unlink` only removes this one node; the subtree it promotes
+            // into `*link` (the sole child, or the in-order predecessor)
+            // may itself still overlap `segment`, so re-examine it.
+            Node::unlink(link);
+            Node::remove_link(link, segment);
+            return;
+        } else if strictly_inside {
+            // The removal range falls strictly inside this segment: keep
+            // `[a, l - 1]` in place and reinsert `[r + 1, b]` to the right.
+            let node = link.as_mut().unwrap();
+            let tail = Segment::new(r.add_one(), mem::replace(&mut node.segment.right, l.sub_one()));
+            Node::insert_link(&mut node.right, tail);
+        } else if trim_left {
+            // Trim whichever endpoint the removal range overlaps; the rest
+            // of the range, if any, lies in the subtree on that side.
+            let node = link.as_mut().unwrap();
+            node.segment.left = r.add_one();
+            Node::remove_link(&mut node.left, segment);
+        } else {
+            let node = link.as_mut().unwrap();
+            node.segment.right = l.sub_one();
+            Node::remove_link(&mut node.right, segment);
+        }
+        Node::rebalance(link);
+    }
 }
 
 impl<T: Ord + Step> Diet<T> {
@@ -148,11 +414,44 @@ impl<T: Ord + Step> Diet<T> {
     /// assert!(diet.contains(&9));
     /// ```
     pub fn insert(&mut self, segment: Segment<T>) {
-        if let Some(ref mut root) = self.root {
-            root.insert(segment);
-        } else {
-            self.root = Some(box Node::new(segment));
-        }
+        Node::insert_link(&mut self.root, segment);
+    }
+
+    /// Remove `value` from `Diet`, splitting or trimming whichever segment
+    /// currently covers it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use diet::{Diet, Segment};
+    ///
+    /// let mut diet: Diet<i32> = Diet::new();
+    /// diet.insert(Segment::new(1, 10));
+    /// diet.remove(&5);
+    /// assert!(diet.contains(&4));
+    /// assert!(!diet.contains(&5));
+    /// assert!(diet.contains(&6));
+    /// ```
+    pub fn remove(&mut self, value: &T) {
+        self.remove_segment(Segment::new(value.clone(), value.clone()));
+    }
+
+    /// Remove every value in `segment` from `Diet`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use diet::{Diet, Segment};
+    ///
+    /// let mut diet: Diet<i32> = Diet::new();
+    /// diet.insert(Segment::new(1, 10));
+    /// diet.remove_segment(Segment::new(3, 5));
+    /// assert!(diet.contains(&2));
+    /// assert!(!diet.contains(&4));
+    /// assert!(diet.contains(&6));
+    /// ```
+    pub fn remove_segment(&mut self, segment: Segment<T>) {
+        Node::remove_link(&mut self.root, &segment);
     }
 
     /// Returns `true` if `Diet` is empty
@@ -182,6 +481,324 @@ impl<T: Ord + Step> Diet<T> {
             false
         }
     }
+
+    /// Returns the total number of integers stored in `Diet`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use diet::{Diet, Segment};
+    ///
+    /// let mut diet: Diet<i32> = Diet::new();
+    /// diet.insert(Segment::new(5, 9));
+    /// diet.insert(Segment::new(20, 20));
+    /// assert_eq!(6, diet.len());
+    /// ```
+    pub fn len(&self) -> usize {
+        Node::count(&self.root)
+    }
+
+    /// Returns the number of maximal segments (nodes) in `Diet`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use diet::{Diet, Segment};
+    ///
+    /// let mut diet: Diet<i32> = Diet::new();
+    /// diet.insert(Segment::new(5, 9));
+    /// diet.insert(Segment::new(20, 20));
+    /// assert_eq!(2, diet.segment_count());
+    /// ```
+    pub fn segment_count(&self) -> usize {
+        Node::node_count(&self.root)
+    }
+
+    /// Returns the `k`-th smallest integer (0-indexed) contained in `Diet`,
+    /// or `None` if `k >= self.len()`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use diet::{Diet, Segment};
+    ///
+    /// let mut diet: Diet<i32> = Diet::new();
+    /// diet.insert(Segment::new(5, 9));
+    /// diet.insert(Segment::new(20, 20));
+    /// assert_eq!(Some(7), diet.nth(2));
+    /// assert_eq!(Some(20), diet.nth(5));
+    /// assert_eq!(None, diet.nth(6));
+    /// ```
+    pub fn nth(&self, k: usize) -> Option<T> {
+        Node::nth(&self.root, k)
+    }
+
+    /// Returns the number of integers in `Diet` that are strictly less than
+    /// `value`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use diet::{Diet, Segment};
+    ///
+    /// let mut diet: Diet<i32> = Diet::new();
+    /// diet.insert(Segment::new(5, 9));
+    /// diet.insert(Segment::new(20, 20));
+    /// assert_eq!(0, diet.rank(&5));
+    /// assert_eq!(3, diet.rank(&8));
+    /// assert_eq!(5, diet.rank(&20));
+    /// ```
+    pub fn rank(&self, value: &T) -> usize {
+        Node::rank(&self.root, value)
+    }
+
+    /// Returns an iterator over the segments of `Diet`, in ascending order,
+    /// without consuming it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use diet::{Diet, Segment};
+    ///
+    /// let mut diet: Diet<i32> = Diet::new();
+    /// diet.insert(Segment::new(5, 9));
+    /// diet.insert(Segment::new(20, 25));
+    /// let v: Vec<&Segment<i32>> = diet.iter().collect();
+    /// assert_eq!(vec![&Segment::new(5, 9), &Segment::new(20, 25)], v);
+    /// ```
+    pub fn iter(&self) -> DietIter<T> {
+        let mut iter = DietIter { stack: Vec::new() };
+        iter.descend(self.root.as_ref().map(|node| &**node));
+        iter
+    }
+
+    /// Returns an iterator over mutable references to the segments of
+    /// `Diet`, in ascending order.
+    ///
+    /// Mutating an endpoint through the yielded `&mut Segment<T>` so that it
+    /// overlaps or touches a neighboring segment is the caller's
+    /// responsibility to avoid; `Diet`'s disjointness invariant is not
+    /// re-checked afterwards.
+    pub fn iter_mut(&mut self) -> DietIterMut<T> {
+        let mut iter = DietIterMut { stack: Vec::new() };
+        iter.descend(self.root.as_mut().map(|node| &mut **node));
+        iter
+    }
+
+    /// Returns a new `Diet` containing every value in `self` or `other`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use diet::{Diet, Segment};
+    ///
+    /// let mut a: Diet<i32> = Diet::new();
+    /// a.insert(Segment::new(1, 5));
+    /// let mut b: Diet<i32> = Diet::new();
+    /// b.insert(Segment::new(4, 10));
+    /// let union = a.union(&b);
+    /// assert_eq!(vec![&Segment::new(1, 10)], union.iter().collect::<Vec<_>>());
+    /// ```
+    pub fn union(&self, other: &Diet<T>) -> Diet<T> {
+        let mut segments = Vec::new();
+        let mut a = self.iter().peekable();
+        let mut b = other.iter().peekable();
+        let mut current: Option<Segment<T>> = None;
+        loop {
+            let take_left = match (a.peek(), b.peek()) {
+                (Some(x), Some(y)) => x.left <= y.left,
+                (Some(_), None) => true,
+                (None, Some(_)) => false,
+                (None, None) => break,
+            };
+            let segment = if take_left { a.next() } else { b.next() }.unwrap();
+            current = Some(match current.take() {
+                None => Segment::new(segment.left.clone(), segment.right.clone()),
+                Some(mut cur) => {
+                    if segment.left <= cur.right.add_one() {
+                        if segment.right > cur.right {
+                            cur.right = segment.right.clone();
+                        }
+                        cur
+                    } else {
+                        segments.push(cur);
+                        Segment::new(segment.left.clone(), segment.right.clone())
+                    }
+                }
+            });
+        }
+        segments.extend(current);
+        segments.into_iter().collect()
+    }
+
+    /// Returns a new `Diet` containing every value in both `self` and `other`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use diet::{Diet, Segment};
+    ///
+    /// let mut a: Diet<i32> = Diet::new();
+    /// a.insert(Segment::new(1, 5));
+    /// let mut b: Diet<i32> = Diet::new();
+    /// b.insert(Segment::new(4, 10));
+    /// let intersection = a.intersection(&b);
+    /// assert_eq!(vec![&Segment::new(4, 5)], intersection.iter().collect::<Vec<_>>());
+    /// ```
+    pub fn intersection(&self, other: &Diet<T>) -> Diet<T> {
+        let mut segments = Vec::new();
+        let mut a = self.iter().peekable();
+        let mut b = other.iter().peekable();
+        loop {
+            let (x, y) = match (a.peek(), b.peek()) {
+                (Some(x), Some(y)) => (x, y),
+                _ => break,
+            };
+            let left = if x.left > y.left { x.left.clone() } else { y.left.clone() };
+            let right = if x.right < y.right { x.right.clone() } else { y.right.clone() };
+            if left <= right {
+                segments.push(Segment::new(left, right));
+            }
+            if x.right < y.right {
+                a.next();
+            } else {
+                b.next();
+            }
+        }
+        segments.into_iter().collect()
+    }
+
+    /// Returns a new `Diet` containing every value in `self` that is not in
+    /// `other`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use diet::{Diet, Segment};
+    ///
+    /// let mut a: Diet<i32> = Diet::new();
+    /// a.insert(Segment::new(1, 10));
+    /// let mut b: Diet<i32> = Diet::new();
+    /// b.insert(Segment::new(4, 6));
+    /// let difference = a.difference(&b);
+    /// assert_eq!(
+    ///     vec![&Segment::new(1, 3), &Segment::new(7, 10)],
+    ///     difference.iter().collect::<Vec<_>>()
+    /// );
+    /// ```
+    pub fn difference(&self, other: &Diet<T>) -> Diet<T> {
+        let mut segments = Vec::new();
+        let mut b = other.iter().peekable();
+        for segment in self.iter() {
+            let mut left = segment.left.clone();
+            loop {
+                let advance_only = match b.peek() {
+                    Some(y) => y.right < left,
+                    None => false,
+                };
+                if advance_only {
+                    b.next();
+                    continue;
+                }
+                let overlaps = match b.peek() {
+                    Some(y) => y.left <= segment.right,
+                    None => false,
+                };
+                if !overlaps {
+                    break;
+                }
+                let y = b.peek().unwrap();
+                if y.left > left {
+                    segments.push(Segment::new(left.clone(), y.left.sub_one()));
+                }
+                if y.right >= segment.right {
+                    left = segment.right.add_one();
+                    break;
+                } else {
+                    left = y.right.add_one();
+                    b.next();
+                }
+            }
+            if left <= segment.right {
+                segments.push(Segment::new(left, segment.right.clone()));
+            }
+        }
+        segments.into_iter().collect()
+    }
+
+    /// Returns a new `Diet` containing every value in exactly one of `self`
+    /// and `other`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use diet::{Diet, Segment};
+    ///
+    /// let mut a: Diet<i32> = Diet::new();
+    /// a.insert(Segment::new(1, 5));
+    /// let mut b: Diet<i32> = Diet::new();
+    /// b.insert(Segment::new(4, 10));
+    /// let symmetric_difference = a.symmetric_difference(&b);
+    /// assert_eq!(
+    ///     vec![&Segment::new(1, 3), &Segment::new(6, 10)],
+    ///     symmetric_difference.iter().collect::<Vec<_>>()
+    /// );
+    /// ```
+    pub fn symmetric_difference(&self, other: &Diet<T>) -> Diet<T> {
+        self.union(other).difference(&self.intersection(other))
+    }
+
+    /// Replaces `self` with `self.union(other)`.
+    pub fn union_with(&mut self, other: &Diet<T>) {
+        *self = self.union(other);
+    }
+
+    /// Replaces `self` with `self.intersection(other)`.
+    pub fn intersect_with(&mut self, other: &Diet<T>) {
+        *self = self.intersection(other);
+    }
+
+    /// Replaces `self` with `self.difference(other)`.
+    pub fn difference_with(&mut self, other: &Diet<T>) {
+        *self = self.difference(other);
+    }
+
+    /// Replaces `self` with `self.symmetric_difference(other)`.
+    pub fn symmetric_difference_with(&mut self, other: &Diet<T>) {
+        *self = self.symmetric_difference(other);
+    }
+}
+
+impl<'a, T: Ord + Step> BitOr<&'a Diet<T>> for &'a Diet<T> {
+    type Output = Diet<T>;
+
+    fn bitor(self, other: &'a Diet<T>) -> Diet<T> {
+        self.union(other)
+    }
+}
+
+impl<'a, T: Ord + Step> BitAnd<&'a Diet<T>> for &'a Diet<T> {
+    type Output = Diet<T>;
+
+    fn bitand(self, other: &'a Diet<T>) -> Diet<T> {
+        self.intersection(other)
+    }
+}
+
+impl<'a, T: Ord + Step> Sub<&'a Diet<T>> for &'a Diet<T> {
+    type Output = Diet<T>;
+
+    fn sub(self, other: &'a Diet<T>) -> Diet<T> {
+        self.difference(other)
+    }
+}
+
+impl<'a, T: Ord + Step> BitXor<&'a Diet<T>> for &'a Diet<T> {
+    type Output = Diet<T>;
+
+    fn bitxor(self, other: &'a Diet<T>) -> Diet<T> {
+        self.symmetric_difference(other)
+    }
 }
 
 impl<T: Ord> Segment<T> {
@@ -217,6 +834,42 @@ impl<T: Ord> Segment<T> {
     }
 }
 
+impl<T: Ord + Step> FromIterator<Segment<T>> for Diet<T> {
+    /// Builds a `Diet` from an iterator of segments: sorts them (O(n log n))
+    /// and coalesces them in a single pass, then constructs a perfectly
+    /// balanced tree in O(n) directly from the resulting sorted, disjoint
+    /// segments, which is faster and better-shaped than inserting them one
+    /// by one.
+    fn from_iter<I: IntoIterator<Item = Segment<T>>>(iter: I) -> Self {
+        let mut segments: Vec<Segment<T>> = iter.into_iter().collect();
+        segments.sort_by(|a, b| a.left.cmp(&b.left));
+        let mut coalesced: Vec<Segment<T>> = Vec::with_capacity(segments.len());
+        for segment in segments {
+            let merge = match coalesced.last() {
+                Some(last) => segment.left <= last.right.add_one(),
+                None => false,
+            };
+            if merge {
+                let last = coalesced.last_mut().unwrap();
+                if segment.right > last.right {
+                    last.right = segment.right;
+                }
+            } else {
+                coalesced.push(segment);
+            }
+        }
+        Diet { root: Node::build_balanced(&coalesced) }
+    }
+}
+
+impl<T: Ord + Step> Extend<Segment<T>> for Diet<T> {
+    fn extend<I: IntoIterator<Item = Segment<T>>>(&mut self, iter: I) {
+        for segment in iter {
+            self.insert(segment);
+        }
+    }
+}
+
 pub struct DietIterator<T: Ord + Step> {
     queue: Vec<Box<Node<T>>>,
 }
@@ -260,9 +913,89 @@ impl<T: Ord + Step> Iterator for DietIterator<T> {
     }
 }
 
+/// Borrowing iterator over the segments of a `Diet`, in ascending order.
+///
+/// Created by [`Diet::iter`].
+pub struct DietIter<'a, T: 'a + Ord + Step> {
+    stack: Vec<&'a Node<T>>,
+}
+
+impl<'a, T: Ord + Step> DietIter<'a, T> {
+    fn descend(&mut self, mut current: Option<&'a Node<T>>) {
+        while let Some(node) = current {
+            current = node.left.as_ref().map(|node| &**node);
+            self.stack.push(node);
+        }
+    }
+}
+
+impl<'a, T: Ord + Step> Iterator for DietIter<'a, T> {
+    type Item = &'a Segment<T>;
+
+    fn next(&mut self) -> Option<&'a Segment<T>> {
+        self.stack.pop().map(|node| {
+            self.descend(node.right.as_ref().map(|node| &**node));
+            &node.segment
+        })
+    }
+}
+
+impl<'a, T: Ord + Step> IntoIterator for &'a Diet<T> {
+    type Item = &'a Segment<T>;
+    type IntoIter = DietIter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// Borrowing iterator over mutable references to the segments of a `Diet`,
+/// in ascending order.
+///
+/// Created by [`Diet::iter_mut`].
+// Each stack entry holds a node's segment and right subtree split apart up
+// front, rather than the whole `&mut Node`: borrowing `node.left` to keep
+// descending and later moving `node` itself onto the stack in the same scope
+// does not borrow-check, since the two borrows are threaded through separate
+// statements instead of being split from `*node` at once.
+pub struct DietIterMut<'a, T: 'a + Ord + Step> {
+    stack: Vec<(&'a mut Segment<T>, &'a mut Link<T>)>,
+}
+
+impl<'a, T: Ord + Step> DietIterMut<'a, T> {
+    fn descend(&mut self, mut current: Option<&'a mut Node<T>>) {
+        while let Some(node) = current {
+            let (segment, left, right) = node.split_mut();
+            current = left.as_mut().map(|node| &mut **node);
+            self.stack.push((segment, right));
+        }
+    }
+}
+
+impl<'a, T: Ord + Step> Iterator for DietIterMut<'a, T> {
+    type Item = &'a mut Segment<T>;
+
+    fn next(&mut self) -> Option<&'a mut Segment<T>> {
+        self.stack.pop().map(|(segment, right)| {
+            self.descend(right.as_mut().map(|node| &mut **node));
+            segment
+        })
+    }
+}
+
+impl<'a, T: Ord + Step> IntoIterator for &'a mut Diet<T> {
+    type Item = &'a mut Segment<T>;
+    type IntoIter = DietIterMut<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter_mut()
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
+    use std::collections::BTreeSet;
 
     #[test]
     fn test_consuming_iterator() {
@@ -274,4 +1007,104 @@ mod test {
         let v: Vec<Segment<i32>> = diet.into_iter().collect();
         assert_eq!(vec![Segment::new(5, 40), Segment::new(100, 200)], v);
     }
+
+    #[test]
+    fn test_remove_segment_reexamines_node_promoted_by_unlink() {
+        let mut diet = Diet::new();
+        diet.insert(Segment::new(24, 26));
+        diet.remove(&25);
+        diet.remove_segment(Segment::new(24, 27));
+        let v: Vec<Segment<i32>> = diet.into_iter().collect();
+        assert!(v.is_empty(), "expected empty diet, got {:?}", v);
+    }
+
+    #[test]
+    fn test_remove_does_not_overflow_at_type_min() {
+        let mut diet = Diet::new();
+        diet.insert(Segment::new(i32::MIN, i32::MIN + 5));
+        diet.remove(&(i32::MIN + 3));
+        let v: Vec<Segment<i32>> = diet.into_iter().collect();
+        assert_eq!(
+            vec![
+                Segment::new(i32::MIN, i32::MIN + 2),
+                Segment::new(i32::MIN + 4, i32::MIN + 5)
+            ],
+            v
+        );
+    }
+
+    #[test]
+    fn test_insert_merges_overlapping_and_adjacent_ranges() {
+        let mut rng = Xorshift64(0x9e37_79b9_7f4a_7c15);
+        for _ in 0..20 {
+            let mut diet: Diet<i32> = Diet::new();
+            let mut reference: BTreeSet<i32> = BTreeSet::new();
+            for _ in 0..200 {
+                // Small, densely-packed ranges over a small domain, so
+                // inserted segments frequently overlap or sit adjacent to
+                // segments already in the tree.
+                let left = rng.below(200) as i32;
+                let right = left + rng.below(5) as i32;
+                diet.insert(Segment::new(left, right));
+                reference.extend(left..=right);
+            }
+            let got: Vec<i32> = diet.iter().flat_map(|s| *s.left()..=*s.right()).collect();
+            let expected: Vec<i32> = reference.into_iter().collect();
+            assert_eq!(expected, got);
+        }
+    }
+
+    // A small xorshift PRNG, so the property test below stays deterministic
+    // without pulling in an external `rand` dependency.
+    struct Xorshift64(u64);
+
+    impl Xorshift64 {
+        fn next_u64(&mut self) -> u64 {
+            let mut x = self.0;
+            x ^= x << 13;
+            x ^= x >> 7;
+            x ^= x << 17;
+            self.0 = x;
+            x
+        }
+
+        fn below(&mut self, bound: usize) -> usize {
+            (self.next_u64() % bound as u64) as usize
+        }
+    }
+
+    #[test]
+    fn test_avl_height_bound_after_random_insert_remove() {
+        let mut rng = Xorshift64(0x2545_f491_4f6c_dd1d);
+        for _ in 0..20 {
+            let mut diet: Diet<i32> = Diet::new();
+            let mut present: Vec<i32> = Vec::new();
+            for _ in 0..500 {
+                if present.is_empty() || rng.below(3) != 0 {
+                    // Points 4 apart never merge into one node, so the tree
+                    // keeps growing rather than collapsing back to a line.
+                    let value = rng.below(1_000_000) as i32 * 4;
+                    diet.insert(Segment::new(value, value));
+                    present.push(value);
+                } else {
+                    let index = rng.below(present.len());
+                    let value = present.swap_remove(index);
+                    diet.remove(&value);
+                }
+
+                let n = diet.segment_count();
+                if n == 0 {
+                    continue;
+                }
+                let bound = 1.44 * ((n as f64 + 2.0).log2());
+                assert!(
+                    f64::from(Node::height(&diet.root)) <= bound + 1e-9,
+                    "height {} exceeds AVL bound {:.3} for n = {}",
+                    Node::height(&diet.root),
+                    bound,
+                    n
+                );
+            }
+        }
+    }
 }